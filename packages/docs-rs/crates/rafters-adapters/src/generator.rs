@@ -0,0 +1,50 @@
+//! Renders a [`TransformedBlock`] as a Web Component `<script>` + element
+//! pair for embedding in a docs page.
+
+use crate::traits::TransformedBlock;
+use crate::util::escape_attr;
+
+/// Emit the `customElements.define` script and the custom element tag for
+/// `block`, with its static props serialized as attributes.
+///
+/// `prerendered` is the component's server-rendered initial HTML, if any
+/// (see [`FrameworkAdapter::prerender`](crate::FrameworkAdapter::prerender)).
+/// It's placed in the element's light DOM so the preview is visible before
+/// the client component upgrades it; adapters that can't prerender pass an
+/// empty string and the element starts out empty, same as before.
+pub fn generate_web_component(block: &TransformedBlock, prerendered: &str) -> String {
+    let attrs: String = block
+        .props
+        .iter()
+        .map(|(name, value)| format!(" {name}=\"{}\"", escape_attr(value)))
+        .collect();
+
+    format!(
+        "<script type=\"module\">\n{}\n</script>\n<{tag}{attrs}>{prerendered}</{tag}>",
+        block.script,
+        tag = block.tag_name,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_prop_defaults_containing_quotes_and_angle_brackets() {
+        let block = TransformedBlock {
+            tag_name: "rafters-greeting".to_string(),
+            script: String::new(),
+            props: vec![(
+                "name".to_string(),
+                r#"Bob "the <b>builder</b>""#.to_string(),
+            )],
+            static_markup: String::new(),
+        };
+
+        let html = generate_web_component(&block, "");
+
+        assert!(html.contains(r#"name="Bob &quot;the <b>builder</b>&quot;""#));
+        assert!(!html.contains(r#"name="Bob "the <b>builder</b>""""#));
+    }
+}
@@ -5,8 +5,15 @@
 
 pub mod generator;
 pub mod react;
+pub mod registry;
+pub mod solid;
 pub mod traits;
+mod util;
+pub mod vue;
 
 pub use generator::generate_web_component;
 pub use react::ReactAdapter;
+pub use registry::AdapterRegistry;
+pub use solid::SolidAdapter;
 pub use traits::{FrameworkAdapter, TransformContext, TransformError, TransformedBlock};
+pub use vue::VueAdapter;
@@ -0,0 +1,165 @@
+//! Adapter for React/JSX component previews.
+
+use crate::traits::{FrameworkAdapter, TransformContext, TransformError, TransformedBlock};
+use crate::util::{escape_attr, escape_text, find_matching_close};
+
+/// Turns a React function component into a Web Component preview.
+pub struct ReactAdapter;
+
+impl FrameworkAdapter for ReactAdapter {
+    fn framework_name(&self) -> &'static str {
+        "react"
+    }
+
+    fn transform(
+        &self,
+        source: &str,
+        ctx: &TransformContext,
+    ) -> Result<TransformedBlock, TransformError> {
+        if source.trim().is_empty() {
+            return Err(TransformError::Parse(
+                "empty React component source".to_string(),
+            ));
+        }
+
+        let tag_name = format!(
+            "rafters-{}",
+            ctx.component_name.to_lowercase().replace('_', "-")
+        );
+
+        let script = format!(
+            "import {{ createRoot }} from 'react-dom/client';\n\
+             import {{ default as Component }} from '{}';\n\
+             customElements.define('{tag_name}', class extends HTMLElement {{\n\
+             \u{20}\u{20}connectedCallback() {{\n\
+             \u{20}\u{20}\u{20}\u{20}createRoot(this).render(Component(this.dataset));\n\
+             \u{20}\u{20}}}\n\
+             }});",
+            ctx.doc_path.display(),
+        );
+
+        Ok(TransformedBlock {
+            tag_name,
+            script,
+            props: extract_props(source),
+            static_markup: extract_markup(source).unwrap_or_default(),
+        })
+    }
+
+    fn prerender(
+        &self,
+        block: &TransformedBlock,
+        _ctx: &TransformContext,
+    ) -> Result<String, TransformError> {
+        // No real JS runtime is available at build time, so we substitute
+        // each prop's static default value (empty string if it declares
+        // none) into the JSX markup captured at transform time, and render
+        // the result as plain markup the client component then hydrates
+        // over.
+        let markup = substitute_props(&block.static_markup, &block.props);
+        let placeholder_attrs: String = block
+            .props
+            .iter()
+            .map(|(name, value)| format!(" data-{name}=\"{}\"", escape_attr(value)))
+            .collect();
+
+        Ok(format!(
+            "<div class=\"preview-fallback\"{placeholder_attrs}>{markup}</div>"
+        ))
+    }
+}
+
+/// Pulls the prop names (and default string-literal values, if any) out of
+/// a function component's destructured parameter, e.g.
+/// `function Greeting({ name = "World" })` -> `[("name", "World")]`.
+fn extract_props(source: &str) -> Vec<(String, String)> {
+    let Some(paren_open) = source.find('(') else {
+        return Vec::new();
+    };
+    let Some(paren_close) = source[paren_open..].find(')').map(|i| i + paren_open) else {
+        return Vec::new();
+    };
+    let params = &source[paren_open + 1..paren_close];
+
+    let Some(brace_open) = params.find('{') else {
+        return Vec::new();
+    };
+    let Some(brace_close) = params.rfind('}') else {
+        return Vec::new();
+    };
+
+    params[brace_open + 1..brace_close]
+        .split(',')
+        .filter_map(|field| {
+            let field = field.trim();
+            if field.is_empty() {
+                return None;
+            }
+            match field.split_once('=') {
+                Some((name, default)) => {
+                    let default = default.trim().trim_matches(['"', '\'']);
+                    Some((name.trim().to_string(), default.to_string()))
+                }
+                None => Some((field.to_string(), String::new())),
+            }
+        })
+        .collect()
+}
+
+/// Pulls the JSX a component returns out of its `return (...)` (or a bare
+/// `return <...>;`), so `prerender` has real markup to substitute prop
+/// values into instead of an empty placeholder.
+fn extract_markup(source: &str) -> Option<String> {
+    let after_return = source.find("return")?;
+    let rest = source[after_return + "return".len()..].trim_start();
+
+    if let Some(body) = rest.strip_prefix('(') {
+        if let Some(close) = find_matching_close(body, '(', ')') {
+            return Some(body[..close].trim().to_string());
+        }
+        return Some(body.trim().to_string());
+    }
+
+    let end = rest.find(';').unwrap_or(rest.len());
+    Some(rest[..end].trim().to_string())
+}
+
+/// Replaces each `{propName}` expression in `markup` with that prop's
+/// static value.
+fn substitute_props(markup: &str, props: &[(String, String)]) -> String {
+    let mut out = markup.to_string();
+    for (name, value) in props {
+        out = out.replace(&format!("{{{name}}}"), &escape_text(value));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_quoted_default_prop() {
+        let source = r#"function Greeting({ name = "Bob\"s" }) { return <p>{name}</p>; }"#;
+        assert_eq!(
+            extract_props(source),
+            vec![("name".to_string(), "Bob\\\"s".to_string())]
+        );
+    }
+
+    #[test]
+    fn extract_markup_is_none_without_return() {
+        let source = "function Greeting({ name }) { const x = name; }";
+        assert_eq!(extract_markup(source), None);
+    }
+
+    #[test]
+    fn substitute_props_round_trips_and_escapes() {
+        let markup = "<p>{name}</p>";
+        let props = vec![("name".to_string(), r#"<b>"Bob"</b>"#.to_string())];
+        assert_eq!(
+            substitute_props(markup, &props),
+            "<p>&lt;b&gt;\"Bob\"&lt;/b&gt;</p>"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Looks up a [`FrameworkAdapter`] by the framework name given in a fence's
+//! info string (e.g. ```` ```jsx solid ````), so `docs.toml` projects can
+//! mix preview sources.
+
+use std::collections::HashMap;
+
+use crate::react::ReactAdapter;
+use crate::solid::SolidAdapter;
+use crate::traits::{FrameworkAdapter, TransformError};
+use crate::vue::VueAdapter;
+
+/// A registry of adapters keyed by framework name, defaulting to the
+/// built-in React, Solid, and Vue adapters.
+pub struct AdapterRegistry {
+    adapters: HashMap<&'static str, Box<dyn FrameworkAdapter + Send + Sync>>,
+}
+
+impl Default for AdapterRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            adapters: HashMap::new(),
+        };
+        registry.register(Box::new(ReactAdapter));
+        registry.register(Box::new(SolidAdapter));
+        registry.register(Box::new(VueAdapter));
+        registry
+    }
+}
+
+impl AdapterRegistry {
+    pub fn register(&mut self, adapter: Box<dyn FrameworkAdapter + Send + Sync>) {
+        self.adapters.insert(adapter.framework_name(), adapter);
+    }
+
+    /// Look up the adapter for `framework`, e.g. `"react"` or `"solid"`.
+    pub fn get(
+        &self,
+        framework: &str,
+    ) -> Result<&(dyn FrameworkAdapter + Send + Sync), TransformError> {
+        self.adapters
+            .get(framework)
+            .map(|adapter| adapter.as_ref())
+            .ok_or_else(|| TransformError::UnsupportedFramework(framework.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_built_in_adapters() {
+        let registry = AdapterRegistry::default();
+        assert_eq!(registry.get("react").unwrap().framework_name(), "react");
+        assert_eq!(registry.get("solid").unwrap().framework_name(), "solid");
+        assert_eq!(registry.get("vue").unwrap().framework_name(), "vue");
+    }
+
+    #[test]
+    fn errors_on_unregistered_framework() {
+        let registry = AdapterRegistry::default();
+        let err = registry.get("svelte").unwrap_err();
+        assert!(matches!(err, TransformError::UnsupportedFramework(name) if name == "svelte"));
+    }
+}
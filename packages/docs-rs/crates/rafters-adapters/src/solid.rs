@@ -0,0 +1,158 @@
+//! Adapter for Solid component previews.
+
+use crate::traits::{FrameworkAdapter, TransformContext, TransformError, TransformedBlock};
+use crate::util::{escape_attr, escape_text, find_matching_close};
+
+/// Turns a Solid function component (signals via `createSignal`) into a Web
+/// Component preview.
+pub struct SolidAdapter;
+
+impl FrameworkAdapter for SolidAdapter {
+    fn framework_name(&self) -> &'static str {
+        "solid"
+    }
+
+    fn transform(
+        &self,
+        source: &str,
+        ctx: &TransformContext,
+    ) -> Result<TransformedBlock, TransformError> {
+        if source.trim().is_empty() {
+            return Err(TransformError::Parse(
+                "empty Solid component source".to_string(),
+            ));
+        }
+
+        let tag_name = format!(
+            "rafters-{}",
+            ctx.component_name.to_lowercase().replace('_', "-")
+        );
+
+        let script = format!(
+            "import {{ render }} from 'solid-js/web';\n\
+             import {{ default as Component }} from '{}';\n\
+             customElements.define('{tag_name}', class extends HTMLElement {{\n\
+             \u{20}\u{20}connectedCallback() {{\n\
+             \u{20}\u{20}\u{20}\u{20}render(() => Component(this.dataset), this);\n\
+             \u{20}\u{20}}}\n\
+             }});",
+            ctx.doc_path.display(),
+        );
+
+        Ok(TransformedBlock {
+            tag_name,
+            script,
+            props: extract_signals(source),
+            static_markup: extract_markup(source).unwrap_or_default(),
+        })
+    }
+
+    fn prerender(
+        &self,
+        block: &TransformedBlock,
+        _ctx: &TransformContext,
+    ) -> Result<String, TransformError> {
+        // No real JS runtime is available at build time, so we substitute
+        // each signal's initial value into its `{getter()}` read in the
+        // JSX captured at transform time, and render the result as plain
+        // markup the client component then hydrates over.
+        let markup = substitute_signals(&block.static_markup, &block.props);
+        let placeholder_attrs: String = block
+            .props
+            .iter()
+            .map(|(name, value)| format!(" data-{name}=\"{}\"", escape_attr(value)))
+            .collect();
+
+        Ok(format!(
+            "<div class=\"preview-fallback\"{placeholder_attrs}>{markup}</div>"
+        ))
+    }
+}
+
+/// Pulls `[getter, setter] = createSignal(initial)` declarations out of a
+/// Solid component, returning `(getter, initial)` pairs, e.g.
+/// `const [count, setCount] = createSignal(0)` -> `[("count", "0")]`.
+fn extract_signals(source: &str) -> Vec<(String, String)> {
+    let mut signals = Vec::new();
+    let mut rest = source;
+
+    while let Some(call) = rest.find("createSignal(") {
+        let before = &rest[..call];
+        if let Some(bracket_open) = before.rfind('[') {
+            if let Some(bracket_close) = before[bracket_open..].find(']') {
+                let names = &before[bracket_open + 1..bracket_open + bracket_close];
+                let getter = names.split(',').next().unwrap_or("").trim();
+
+                let args_start = call + "createSignal(".len();
+                if let Some(args_end) = find_matching_close(&rest[args_start..], '(', ')') {
+                    let arg = rest[args_start..args_start + args_end].trim();
+                    let initial = arg.trim_matches(['"', '\'']).to_string();
+                    if !getter.is_empty() {
+                        signals.push((getter.to_string(), initial));
+                    }
+                }
+            }
+        }
+        rest = &rest[call + "createSignal(".len()..];
+    }
+
+    signals
+}
+
+/// Pulls the JSX a component returns out of its `return (...)` (or a bare
+/// `return <...>;`), so `prerender` has real markup to substitute signal
+/// values into instead of an empty placeholder.
+fn extract_markup(source: &str) -> Option<String> {
+    let after_return = source.find("return")?;
+    let rest = source[after_return + "return".len()..].trim_start();
+
+    if let Some(body) = rest.strip_prefix('(') {
+        if let Some(close) = find_matching_close(body, '(', ')') {
+            return Some(body[..close].trim().to_string());
+        }
+        return Some(body.trim().to_string());
+    }
+
+    let end = rest.find(';').unwrap_or(rest.len());
+    Some(rest[..end].trim().to_string())
+}
+
+/// Replaces each `{getter()}` read in `markup` with that signal's static
+/// initial value.
+fn substitute_signals(markup: &str, signals: &[(String, String)]) -> String {
+    let mut out = markup.to_string();
+    for (name, value) in signals {
+        out = out.replace(&format!("{{{name}()}}"), &escape_text(value));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_quoted_signal_initial_value() {
+        let source = r#"const [name, setName] = createSignal("<b>Bob\"s</b>");"#;
+        assert_eq!(
+            extract_signals(source),
+            vec![("name".to_string(), "<b>Bob\\\"s</b>".to_string())]
+        );
+    }
+
+    #[test]
+    fn extract_markup_is_none_without_return() {
+        let source = "function Counter() { const [count] = createSignal(0); }";
+        assert_eq!(extract_markup(source), None);
+    }
+
+    #[test]
+    fn substitute_signals_round_trips_and_escapes() {
+        let markup = "<p>{count()}</p>";
+        let signals = vec![("count".to_string(), "<b>0</b>".to_string())];
+        assert_eq!(
+            substitute_signals(markup, &signals),
+            "<p>&lt;b&gt;0&lt;/b&gt;</p>"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Shared types implemented by every framework adapter.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// A parsed component fence, reduced to the shape
+/// [`generate_web_component`](crate::generate_web_component) needs to emit
+/// a Web Component: a tag name, the client-side script that defines it, and
+/// the static props to seed it with.
+#[derive(Debug, Clone)]
+pub struct TransformedBlock {
+    pub tag_name: String,
+    pub script: String,
+    pub props: Vec<(String, String)>,
+    /// The component's raw returned markup, captured at transform time, for
+    /// adapters that can approximate a prerender by substituting `props`'
+    /// static values into it. Empty for adapters that don't support
+    /// prerendering.
+    pub static_markup: String,
+}
+
+/// Context a [`FrameworkAdapter`] needs while transforming a fence: which
+/// doc it came from and what to name the resulting custom element.
+#[derive(Debug, Clone, Default)]
+pub struct TransformContext {
+    pub doc_path: PathBuf,
+    pub component_name: String,
+}
+
+#[derive(Debug, Error)]
+pub enum TransformError {
+    #[error("failed to parse component source: {0}")]
+    Parse(String),
+    #[error("unsupported framework: {0}")]
+    UnsupportedFramework(String),
+}
+
+/// Converts a framework's component syntax into the shared
+/// [`TransformedBlock`] representation used to preview it as a Web
+/// Component.
+pub trait FrameworkAdapter {
+    /// The info-string tag that selects this adapter, e.g. `"react"`.
+    fn framework_name(&self) -> &'static str;
+
+    /// Parse `source` (the fenced code block's contents) into a
+    /// `TransformedBlock`.
+    fn transform(
+        &self,
+        source: &str,
+        ctx: &TransformContext,
+    ) -> Result<TransformedBlock, TransformError>;
+
+    /// Render the component's initial static HTML at build time, so the
+    /// preview is visible before the client upgrades it. Adapters that
+    /// can't prerender (no static evaluation path) can leave this as-is.
+    fn prerender(
+        &self,
+        _block: &TransformedBlock,
+        _ctx: &TransformContext,
+    ) -> Result<String, TransformError> {
+        Ok(String::new())
+    }
+}
@@ -0,0 +1,63 @@
+//! String-munging helpers shared by the React, Solid, and Vue adapters: the
+//! escaping they all need before interpolating captured prop/signal/ref
+//! values into HTML, and the bracket-matching they all need to pull a
+//! JS/JSX expression's argument out of source text.
+
+/// Escapes `value` for embedding inside a double-quoted HTML attribute.
+pub(crate) fn escape_attr(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+/// Escapes `value` for embedding as HTML text content.
+pub(crate) fn escape_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Index, within `s`, of the `close` that matches the `open` already
+/// consumed just before `s` started.
+pub(crate) fn find_matching_close(s: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 1usize;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_attr_quotes_and_amp() {
+        assert_eq!(
+            escape_attr(r#"Bob "the builder" & co"#),
+            "Bob &quot;the builder&quot; &amp; co"
+        );
+    }
+
+    #[test]
+    fn escapes_text_angle_brackets() {
+        assert_eq!(escape_text("<script>"), "&lt;script&gt;");
+    }
+
+    #[test]
+    fn finds_matching_close_with_nesting() {
+        let s = "(a, b)) + rest";
+        assert_eq!(find_matching_close(s, '(', ')'), Some(6));
+    }
+
+    #[test]
+    fn find_matching_close_returns_none_when_unbalanced() {
+        assert_eq!(find_matching_close("no closing paren", '(', ')'), None);
+    }
+}
@@ -0,0 +1,170 @@
+//! Adapter for Vue single-file-component previews.
+
+use crate::traits::{FrameworkAdapter, TransformContext, TransformError, TransformedBlock};
+use crate::util::{escape_attr, escape_text, find_matching_close};
+
+/// Turns a Vue SFC (`<template>` + `ref`) into a Web Component preview.
+pub struct VueAdapter;
+
+impl FrameworkAdapter for VueAdapter {
+    fn framework_name(&self) -> &'static str {
+        "vue"
+    }
+
+    fn transform(
+        &self,
+        source: &str,
+        ctx: &TransformContext,
+    ) -> Result<TransformedBlock, TransformError> {
+        if !source.contains("<template>") {
+            return Err(TransformError::Parse(
+                "Vue component source is missing a <template> block".to_string(),
+            ));
+        }
+
+        let tag_name = format!(
+            "rafters-{}",
+            ctx.component_name.to_lowercase().replace('_', "-")
+        );
+
+        let script = format!(
+            "import {{ createApp }} from 'vue';\n\
+             import Component from '{}';\n\
+             customElements.define('{tag_name}', class extends HTMLElement {{\n\
+             \u{20}\u{20}connectedCallback() {{\n\
+             \u{20}\u{20}\u{20}\u{20}createApp(Component, {{ ...this.dataset }}).mount(this);\n\
+             \u{20}\u{20}}}\n\
+             }});",
+            ctx.doc_path.display(),
+        );
+
+        Ok(TransformedBlock {
+            tag_name,
+            script,
+            props: extract_refs(source),
+            static_markup: extract_template(source).unwrap_or_default(),
+        })
+    }
+
+    fn prerender(
+        &self,
+        block: &TransformedBlock,
+        _ctx: &TransformContext,
+    ) -> Result<String, TransformError> {
+        // No real JS runtime is available at build time, so we substitute
+        // each `ref`'s initial value into its `{{ name }}` interpolation in
+        // the `<template>` captured at transform time, and render the
+        // result as plain markup the client component then hydrates over.
+        let markup = substitute_refs(&block.static_markup, &block.props);
+        let placeholder_attrs: String = block
+            .props
+            .iter()
+            .map(|(name, value)| format!(" data-{name}=\"{}\"", escape_attr(value)))
+            .collect();
+
+        Ok(format!(
+            "<div class=\"preview-fallback\"{placeholder_attrs}>{markup}</div>"
+        ))
+    }
+}
+
+/// Pulls the contents of a Vue SFC's `<template>` block, so `prerender` has
+/// real markup to substitute `ref` values into instead of an empty
+/// placeholder.
+fn extract_template(source: &str) -> Option<String> {
+    let start = source.find("<template>")? + "<template>".len();
+    let end = source[start..].find("</template>")? + start;
+    Some(source[start..end].trim().to_string())
+}
+
+/// Pulls `const name = ref(initial)` declarations out of a Vue component,
+/// returning `(name, initial)` pairs, e.g. `const count = ref(0)` ->
+/// `[("count", "0")]`.
+fn extract_refs(source: &str) -> Vec<(String, String)> {
+    let mut refs = Vec::new();
+    let mut rest = source;
+
+    while let Some(call) = rest.find("ref(") {
+        let before = &rest[..call];
+        if let Some(eq) = before.rfind('=') {
+            let name = before[..eq]
+                .trim_end()
+                .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .next()
+                .unwrap_or("");
+
+            let args_start = call + "ref(".len();
+            if let Some(args_end) = find_matching_close(&rest[args_start..], '(', ')') {
+                let arg = rest[args_start..args_start + args_end].trim();
+                let initial = arg.trim_matches(['"', '\'']).to_string();
+                if !name.is_empty() {
+                    refs.push((name.to_string(), initial));
+                }
+            }
+        }
+        rest = &rest[call + "ref(".len()..];
+    }
+
+    refs
+}
+
+/// Replaces each `{{ name }}` interpolation in `markup` with that ref's
+/// static initial value; leaves unrecognized expressions untouched.
+fn substitute_refs(markup: &str, refs: &[(String, String)]) -> String {
+    let mut out = String::with_capacity(markup.len());
+    let mut rest = markup;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            out.push_str("{{");
+            rest = after;
+            continue;
+        };
+
+        let expr = after[..end].trim();
+        match refs.iter().find(|(name, _)| name == expr) {
+            Some((_, value)) => out.push_str(&escape_text(value)),
+            None => {
+                out.push_str("{{");
+                out.push_str(&after[..end]);
+                out.push_str("}}");
+            }
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_quoted_ref_initial_value() {
+        let source = r#"const name = ref("<b>Bob\"s</b>");"#;
+        assert_eq!(
+            extract_refs(source),
+            vec![("name".to_string(), "<b>Bob\\\"s</b>".to_string())]
+        );
+    }
+
+    #[test]
+    fn extract_template_is_none_without_template_block() {
+        let source = "const count = ref(0);";
+        assert_eq!(extract_template(source), None);
+    }
+
+    #[test]
+    fn substitute_refs_round_trips_and_escapes() {
+        let markup = "<p>{{ count }}</p>";
+        let refs = vec![("count".to_string(), "<b>0</b>".to_string())];
+        assert_eq!(
+            substitute_refs(markup, &refs),
+            "<p>&lt;b&gt;0&lt;/b&gt;</p>"
+        );
+    }
+}
@@ -1,10 +1,11 @@
 //! Static site build command.
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
-use anyhow::Result;
-use rafters_static::{BuildConfig, StaticBuilder};
+use anyhow::{Context, Result};
+use rafters_static::{AssetPipeline, BuildConfig, StaticBuilder, Theme};
 use serde::Deserialize;
 
 /// Configuration file structure (docs.toml).
@@ -16,6 +17,9 @@ struct ConfigFile {
     components: ComponentsConfig,
     #[serde(default)]
     build: BuildSettings,
+    /// Extra named themes, e.g. `dracula = "themes/dracula.css"`.
+    #[serde(default)]
+    themes: HashMap<String, String>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -39,6 +43,10 @@ struct ComponentsConfig {
 struct BuildSettings {
     #[serde(default = "default_minify")]
     minify: bool,
+    #[serde(default = "default_highlight_theme")]
+    highlight_theme: String,
+    #[serde(default = "default_search")]
+    search: bool,
 }
 
 fn default_docs_dir() -> String {
@@ -56,6 +64,12 @@ fn default_base_url() -> String {
 fn default_minify() -> bool {
     true
 }
+fn default_highlight_theme() -> String {
+    "github-dark".to_string()
+}
+fn default_search() -> bool {
+    true
+}
 
 /// Load configuration from docs.toml if it exists.
 fn load_config() -> ConfigFile {
@@ -79,11 +93,36 @@ fn load_config() -> ConfigFile {
     ConfigFile::default()
 }
 
+/// Read and validate each configured theme file, failing the build if one
+/// doesn't parse as CSS.
+fn load_themes(themes: &HashMap<String, String>) -> Result<Vec<Theme>> {
+    themes
+        .iter()
+        .map(|(name, path)| {
+            let css = fs::read_to_string(path)
+                .with_context(|| format!("failed to read theme file {path} for [themes].{name}"))?;
+            // `css` is bare `--color-*` declarations (see `Theme::css`'s doc
+            // comment), not a standalone stylesheet, so wrap it in a
+            // throwaway selector before handing it to the CSS parser.
+            AssetPipeline::minify_css(&format!(":root {{ {css} }}"))
+                .map_err(|e| anyhow::anyhow!("theme {name} ({path}) failed to parse: {e}"))?;
+            Ok(Theme {
+                name: name.clone(),
+                css,
+            })
+        })
+        .collect()
+}
+
 /// Run the build command.
-pub async fn run(output: Option<PathBuf>, minify: Option<bool>) -> Result<()> {
+///
+/// `no_search` mirrors the CLI's `--no-search` flag, overriding
+/// `build.search` from `docs.toml` when set.
+pub async fn run(output: Option<PathBuf>, minify: Option<bool>, no_search: bool) -> Result<()> {
     tracing::info!("Building static site...");
 
     let file_config = load_config();
+    let themes = load_themes(&file_config.themes)?;
 
     let config = BuildConfig {
         docs_dir: PathBuf::from(&file_config.docs.dir),
@@ -92,6 +131,9 @@ pub async fn run(output: Option<PathBuf>, minify: Option<bool>) -> Result<()> {
         minify: minify.unwrap_or(file_config.build.minify),
         base_url: file_config.docs.base_url,
         title: file_config.docs.title,
+        highlight_theme: file_config.build.highlight_theme,
+        search: file_config.build.search && !no_search,
+        themes,
     };
 
     let result = StaticBuilder::new(config).build().await?;
@@ -107,3 +149,24 @@ pub async fn run(output: Option<PathBuf>, minify: Option<bool>) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_themes_accepts_bare_declarations() {
+        let path = std::env::temp_dir().join("rafters-docs-test-theme-dracula.css");
+        fs::write(&path, "--color-bg: #282a36;\n--color-text: #f8f8f2;").unwrap();
+
+        let mut themes = HashMap::new();
+        themes.insert("dracula".to_string(), path.to_string_lossy().to_string());
+
+        let loaded = load_themes(&themes).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "dracula");
+        assert!(loaded[0].css.contains("--color-bg"));
+    }
+}
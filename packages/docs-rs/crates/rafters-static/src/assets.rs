@@ -1,12 +1,34 @@
 //! Asset pipeline for CSS and JavaScript processing.
 
+use crate::highlight;
+
+/// A named color theme, registered via `docs.toml`'s `[themes]` table and
+/// emitted as a `[data-theme="name"]` rule set alongside the built-in
+/// light/dark palettes.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    /// Raw `--color-*` variable declarations for this theme, without the
+    /// surrounding `[data-theme="..."]` selector.
+    pub css: String,
+}
+
 /// Asset pipeline utilities.
 pub struct AssetPipeline;
 
 impl AssetPipeline {
-    /// Generate the main CSS file.
-    pub fn generate_css() -> String {
-        DEFAULT_CSS.to_string()
+    /// Generate the main CSS file, concatenating the built-in light/dark
+    /// palette, the `highlight_theme`-keyed token colors, and any extra
+    /// registered `themes`.
+    pub fn generate_css(themes: &[Theme], highlight_theme: &str) -> String {
+        let mut css = format!("{DEFAULT_CSS}{}", highlight::token_css(highlight_theme));
+        for theme in themes {
+            css.push_str(&format!(
+                "\n[data-theme=\"{}\"] {{\n{}\n}}\n",
+                theme.name, theme.css
+            ));
+        }
+        css
     }
 
     /// Generate the main JavaScript file.
@@ -14,6 +36,23 @@ impl AssetPipeline {
         DEFAULT_JS.to_string()
     }
 
+    /// Generate `search.js`, the client-side query engine for
+    /// `search-index.json`. Only emitted when `build.search` is enabled.
+    pub fn generate_search_js() -> String {
+        SEARCH_JS.to_string()
+    }
+
+    /// A tiny script meant to run in `<head>`, before any stylesheet or
+    /// other script: it reads the stored theme (or the system preference)
+    /// and sets `data-theme` on `<html>` so the page never flashes the
+    /// default palette before `app.js` loads. `StaticBuilder::write_assets`
+    /// writes this to `theme-init.js`; this crate renders page bodies only
+    /// (no `<html>`/`<head>` shell), so whatever template wraps them is
+    /// responsible for inlining it ahead of `styles.css`.
+    pub fn theme_init_script() -> String {
+        THEME_INIT_SCRIPT.to_string()
+    }
+
     /// Minify CSS using lightningcss.
     pub fn minify_css(css: &str) -> Result<String, String> {
         use lightningcss::stylesheet::{ParserOptions, PrinterOptions, StyleSheet};
@@ -238,6 +277,24 @@ body {
   color: var(--color-text);
 }
 
+.toc a.active {
+  color: var(--color-primary);
+  font-weight: 600;
+}
+
+.anchor {
+  margin-right: 0.5rem;
+  color: var(--color-text-secondary);
+  text-decoration: none;
+  opacity: 0;
+}
+
+h2:hover .anchor,
+h3:hover .anchor,
+h4:hover .anchor {
+  opacity: 1;
+}
+
 .toc-level-3 {
   padding-left: 1rem;
 }
@@ -272,7 +329,7 @@ body {
 }
 "#;
 
-const DEFAULT_JS: &str = r#"// Rafters Docs - Generated JavaScript
+const DEFAULT_JS: &str = r##"// Rafters Docs - Generated JavaScript
 (function() {
   'use strict';
 
@@ -309,6 +366,133 @@ const DEFAULT_JS: &str = r#"// Rafters Docs - Generated JavaScript
     pre.style.position = 'relative';
     pre.appendChild(btn);
   });
+
+  // Theme picker
+  const THEME_KEY = 'rafters-theme';
+  const picker = document.querySelector('[data-theme-picker]');
+  if (picker) {
+    picker.value = document.documentElement.getAttribute('data-theme') || '';
+    picker.addEventListener('change', () => {
+      const theme = picker.value;
+      if (theme) {
+        localStorage.setItem(THEME_KEY, theme);
+        document.documentElement.setAttribute('data-theme', theme);
+      } else {
+        localStorage.removeItem(THEME_KEY);
+        document.documentElement.removeAttribute('data-theme');
+      }
+    });
+  }
+
+  // Click-to-copy heading anchors
+  document.querySelectorAll('.anchor').forEach(anchor => {
+    anchor.addEventListener('click', event => {
+      event.preventDefault();
+      const url = `${location.origin}${location.pathname}${anchor.getAttribute('href')}`;
+      navigator.clipboard.writeText(url);
+      history.replaceState(null, '', anchor.getAttribute('href'));
+    });
+  });
+
+  // Scroll-spy TOC
+  const tocLinks = document.querySelectorAll('.toc a');
+  const headings = [...document.querySelectorAll('.content h2, .content h3, .content h4')];
+
+  if (tocLinks.length && headings.length && 'IntersectionObserver' in window) {
+    const linkForId = id => document.querySelector(`.toc a[href="#${id}"]`);
+
+    const observer = new IntersectionObserver(entries => {
+      for (const entry of entries) {
+        const link = linkForId(entry.target.id);
+        if (!link) continue;
+        if (entry.isIntersecting) {
+          tocLinks.forEach(l => l.classList.remove('active'));
+          link.classList.add('active');
+        }
+      }
+    }, { rootMargin: '0px 0px -70% 0px' });
+
+    headings.forEach(heading => observer.observe(heading));
+
+    tocLinks.forEach(link => {
+      link.addEventListener('click', event => {
+        const id = link.getAttribute('href').slice(1);
+        const target = document.getElementById(id);
+        if (!target) return;
+        event.preventDefault();
+        target.scrollIntoView({ behavior: 'smooth' });
+      });
+    });
+  }
+})();
+"##;
+
+/// Inlined in `<head>` ahead of `app.js` so the stored (or system-preferred)
+/// theme applies before first paint.
+const THEME_INIT_SCRIPT: &str = r#"(function() {
+  try {
+    var stored = localStorage.getItem('rafters-theme');
+    if (stored) {
+      document.documentElement.setAttribute('data-theme', stored);
+    } else if (window.matchMedia('(prefers-color-scheme: dark)').matches) {
+      document.documentElement.setAttribute('data-theme', 'dark');
+    }
+  } catch (e) {}
+})();"#;
+
+const SEARCH_JS: &str = r#"// Rafters Docs - Search
+(function() {
+  'use strict';
+
+  const input = document.querySelector('[data-search-input]');
+  const results = document.querySelector('[data-search-results]');
+  if (!input || !results) return;
+
+  const STOPWORDS = new Set(["a","an","and","are","as","at","be","by","for","from","has","he","in","is","it","its","of","on","that","the","to","was","were","will","with"]);
+
+  function tokenize(text) {
+    return text
+      .split(/[^\p{L}\p{N}]+/u)
+      .filter(Boolean)
+      .map(w => w.toLowerCase())
+      .filter(w => !STOPWORDS.has(w));
+  }
+
+  let index = null;
+  fetch('search-index.json').then(r => r.json()).then(data => { index = data; });
+
+  function search(query) {
+    if (!index) return [];
+    const scores = new Map();
+    for (const token of tokenize(query)) {
+      const postings = index.postings[token];
+      if (!postings) continue;
+      for (const p of postings) {
+        const weighted = p.term_frequency * p.field_weight;
+        scores.set(p.doc_id, (scores.get(p.doc_id) || 0) + weighted);
+      }
+    }
+    return [...scores.entries()]
+      .sort((a, b) => b[1] - a[1])
+      .slice(0, 10)
+      .map(([docId]) => index.docs[docId]);
+  }
+
+  function render(docs) {
+    results.innerHTML = '';
+    for (const doc of docs) {
+      const link = document.createElement('a');
+      link.href = doc.anchor ? `${doc.url}#${doc.anchor}` : doc.url;
+      link.textContent = doc.title;
+      results.appendChild(link);
+    }
+    results.hidden = docs.length === 0;
+  }
+
+  input.addEventListener('input', () => {
+    const query = input.value.trim();
+    render(query ? search(query) : []);
+  });
 })();
 "#;
 
@@ -318,17 +502,50 @@ mod tests {
 
     #[test]
     fn generates_css() {
-        let css = AssetPipeline::generate_css();
+        let css = AssetPipeline::generate_css(&[], "github-dark");
         assert!(css.contains(":root"));
         assert!(css.contains("--color-bg"));
     }
 
+    #[test]
+    fn generates_css_token_rules() {
+        let css = AssetPipeline::generate_css(&[], "github-dark");
+        assert!(css.contains(".tok-kw"));
+        assert!(css.contains(".tok-str"));
+    }
+
+    #[test]
+    fn generates_css_varies_token_colors_by_highlight_theme() {
+        let dark = AssetPipeline::generate_css(&[], "github-dark");
+        let light = AssetPipeline::generate_css(&[], "github-light");
+        assert_ne!(dark, light);
+    }
+
+    #[test]
+    fn generates_css_for_extra_themes() {
+        let css = AssetPipeline::generate_css(
+            &[Theme {
+                name: "dracula".to_string(),
+                css: "--color-bg: #282a36;".to_string(),
+            }],
+            "github-dark",
+        );
+        assert!(css.contains("[data-theme=\"dracula\"]"));
+        assert!(css.contains("--color-bg: #282a36;"));
+    }
+
     #[test]
     fn generates_js() {
         let js = AssetPipeline::generate_js();
         assert!(js.contains("addEventListener"));
     }
 
+    #[test]
+    fn generates_search_js() {
+        let js = AssetPipeline::generate_search_js();
+        assert!(js.contains("search-index.json"));
+    }
+
     #[test]
     fn minifies_css() {
         let css = r#"
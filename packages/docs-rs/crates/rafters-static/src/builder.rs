@@ -0,0 +1,380 @@
+//! Orchestrates a full static build: walk the docs tree, render Markdown to
+//! HTML, and write pages plus assets to the output directory.
+
+use std::path::PathBuf;
+use std::time::Instant;
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use rafters_adapters::{generate_web_component, AdapterRegistry, TransformContext};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+use crate::assets::{AssetPipeline, Theme};
+use crate::highlight::{self, highlight_code};
+use crate::search::{self, SearchRecord};
+
+/// Configuration for a single [`StaticBuilder::build`] run.
+#[derive(Debug, Clone)]
+pub struct BuildConfig {
+    pub docs_dir: PathBuf,
+    pub output_dir: PathBuf,
+    pub components_dir: Option<PathBuf>,
+    pub minify: bool,
+    pub base_url: String,
+    pub title: String,
+    /// Name of the built-in syntax highlighting theme to use for fenced
+    /// code blocks, e.g. `"github-dark"`.
+    pub highlight_theme: String,
+    /// Whether to emit `search-index.json` and `search.js`.
+    pub search: bool,
+    /// Extra named themes declared under `docs.toml`'s `[themes]` table,
+    /// already validated to parse as CSS.
+    pub themes: Vec<Theme>,
+}
+
+/// Summary of a completed build, reported back to the CLI.
+#[derive(Debug, Clone)]
+pub struct BuildResult {
+    pub pages: usize,
+    pub components: usize,
+    pub duration_ms: u128,
+    pub output_dir: PathBuf,
+}
+
+#[derive(Debug, Error)]
+pub enum BuildError {
+    #[error("failed to read docs directory {path}: {source}")]
+    ReadDocs {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to write output file {path}: {source}")]
+    WriteOutput {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to minify css: {0}")]
+    Minify(String),
+    #[error("failed to serialize search index: {0}")]
+    SearchIndex(#[from] serde_json::Error),
+}
+
+/// Builds a static site from a [`BuildConfig`].
+pub struct StaticBuilder {
+    config: BuildConfig,
+    adapters: AdapterRegistry,
+}
+
+impl StaticBuilder {
+    pub fn new(config: BuildConfig) -> Self {
+        Self {
+            config,
+            adapters: AdapterRegistry::default(),
+        }
+    }
+
+    /// Render every Markdown page under `docs_dir` and write the result,
+    /// plus the shared CSS/JS assets, into `output_dir`.
+    pub async fn build(&self) -> Result<BuildResult, BuildError> {
+        let started = Instant::now();
+        std::fs::create_dir_all(&self.config.output_dir).map_err(|source| {
+            BuildError::WriteOutput {
+                path: self.config.output_dir.clone(),
+                source,
+            }
+        })?;
+
+        let mut pages = 0;
+        let mut components = 0;
+        let mut search_records = Vec::new();
+
+        for entry in WalkDir::new(&self.config.docs_dir) {
+            let entry = entry.map_err(|e| BuildError::ReadDocs {
+                path: self.config.docs_dir.clone(),
+                source: std::io::Error::new(std::io::ErrorKind::Other, e),
+            })?;
+
+            if entry.path().extension().and_then(|e| e.to_str()) != Some("md") {
+                continue;
+            }
+
+            let source =
+                std::fs::read_to_string(entry.path()).map_err(|source| BuildError::ReadDocs {
+                    path: entry.path().to_path_buf(),
+                    source,
+                })?;
+
+            let relative = entry
+                .path()
+                .strip_prefix(&self.config.docs_dir)
+                .unwrap_or(entry.path())
+                .with_extension("html");
+            let url = format!(
+                "{}{}",
+                self.config.base_url.trim_end_matches('/'),
+                relative.to_string_lossy().replace('\\', "/")
+            );
+
+            let (html, block_count, title, body_text, headings) =
+                self.render_page(&source, entry.path());
+            components += block_count;
+
+            if self.config.search {
+                search_records.push(SearchRecord {
+                    title: title.clone(),
+                    url: url.clone(),
+                    anchor: None,
+                    body: body_text,
+                });
+                for (heading_text, slug) in headings {
+                    search_records.push(SearchRecord {
+                        title: heading_text.clone(),
+                        url: url.clone(),
+                        anchor: Some(slug),
+                        body: heading_text,
+                    });
+                }
+            }
+
+            let out_path = self.config.output_dir.join(&relative);
+            if let Some(parent) = out_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|source| BuildError::WriteOutput {
+                    path: parent.to_path_buf(),
+                    source,
+                })?;
+            }
+            std::fs::write(&out_path, html).map_err(|source| BuildError::WriteOutput {
+                path: out_path.clone(),
+                source,
+            })?;
+            pages += 1;
+        }
+
+        self.write_assets()?;
+        if self.config.search {
+            self.write_search_index(&search_records)?;
+        }
+
+        Ok(BuildResult {
+            pages,
+            components,
+            duration_ms: started.elapsed().as_millis(),
+            output_dir: self.config.output_dir.clone(),
+        })
+    }
+
+    /// Render a single Markdown document to HTML, syntax-highlighting
+    /// fenced code blocks and expanding `jsx` fences into prerendered Web
+    /// Component previews. Returns the HTML, the number of component
+    /// preview blocks encountered, the page title (first `h1`, or empty),
+    /// the plain-text body used for search indexing, and the `(heading
+    /// text, slug)` of every H2-H4 so the caller can index them as
+    /// deep-linkable sections.
+    fn render_page(
+        &self,
+        source: &str,
+        doc_path: &std::path::Path,
+    ) -> (String, usize, String, String, Vec<(String, String)>) {
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        options.insert(Options::ENABLE_FOOTNOTES);
+
+        let mut html = String::new();
+        let mut components = 0;
+        let mut in_code_block: Option<String> = None;
+        let mut code_buf = String::new();
+        let mut events = Vec::new();
+        let mut title = String::new();
+        let mut in_h1 = false;
+        let mut used_slugs = std::collections::HashMap::new();
+        let mut anchored_heading: Option<pulldown_cmark::HeadingLevel> = None;
+        let mut heading_events: Vec<Event> = Vec::new();
+        let mut headings: Vec<(String, String)> = Vec::new();
+
+        for event in Parser::new_ext(source, options) {
+            match &event {
+                Event::Start(Tag::Heading { level, .. })
+                    if title.is_empty() && *level == pulldown_cmark::HeadingLevel::H1 =>
+                {
+                    in_h1 = true;
+                }
+                Event::End(TagEnd::Heading(pulldown_cmark::HeadingLevel::H1)) if in_h1 => {
+                    in_h1 = false;
+                }
+                Event::Text(text) if in_h1 => title.push_str(text),
+                _ => {}
+            }
+
+            match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                    in_code_block = Some(info.to_string());
+                    code_buf.clear();
+                }
+                Event::Text(text) if in_code_block.is_some() => {
+                    code_buf.push_str(&text);
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    if let Some(info) = in_code_block.take() {
+                        let mut tokens = info.split_whitespace();
+                        let lang = tokens.next().unwrap_or("");
+                        if lang == "jsx" {
+                            let framework = tokens.next().unwrap_or("react");
+                            components += 1;
+                            events.push(Event::Html(
+                                self.render_component_preview(
+                                    &code_buf, framework, components, doc_path,
+                                )
+                                .into(),
+                            ));
+                        } else {
+                            let highlighted = highlight_code(&code_buf, lang);
+                            events.push(Event::Html(format!("<pre><code class=\"language-{lang}\">{highlighted}</code></pre>").into()));
+                        }
+                    }
+                }
+                Event::Start(Tag::Heading { level, .. })
+                    if matches!(
+                        level,
+                        pulldown_cmark::HeadingLevel::H2
+                            | pulldown_cmark::HeadingLevel::H3
+                            | pulldown_cmark::HeadingLevel::H4
+                    ) =>
+                {
+                    anchored_heading = Some(level);
+                    heading_events.clear();
+                }
+                Event::End(TagEnd::Heading(level)) if anchored_heading == Some(level) => {
+                    anchored_heading = None;
+                    let mut heading_html = String::new();
+                    pulldown_cmark::html::push_html(&mut heading_html, heading_events.drain(..));
+                    let heading_text = search::strip_html(&heading_html);
+                    let slug = crate::slug::slugify(&heading_text, &mut used_slugs);
+                    let tag = heading_html_tag(level);
+                    events.push(Event::Html(
+                        format!(
+                            "<{tag} id=\"{slug}\"><a class=\"anchor\" href=\"#{slug}\" aria-hidden=\"true\">#</a>{heading_html}</{tag}>"
+                        )
+                        .into(),
+                    ));
+                    headings.push((heading_text, slug));
+                }
+                event if anchored_heading.is_some() => heading_events.push(event),
+                other => events.push(other),
+            }
+        }
+
+        pulldown_cmark::html::push_html(&mut html, events.into_iter());
+        let body_text = search::strip_html(&html);
+        (html, components, title, body_text, headings)
+    }
+
+    /// Transform a ```` ```jsx <framework> ```` fence into a prerendered Web
+    /// Component preview: the custom element's light DOM is seeded with
+    /// server-rendered markup so the preview is visible before the client
+    /// component hydrates. `framework` selects the adapter (`"react"`,
+    /// `"solid"`, `"vue"`, ...) via the registry.
+    fn render_component_preview(
+        &self,
+        source: &str,
+        framework: &str,
+        index: usize,
+        doc_path: &std::path::Path,
+    ) -> String {
+        let ctx = TransformContext {
+            doc_path: doc_path.to_path_buf(),
+            component_name: format!("Component{index}"),
+        };
+
+        let adapter = match self.adapters.get(framework) {
+            Ok(adapter) => adapter,
+            Err(e) => {
+                return format!(
+                    "<pre><code class=\"language-jsx\">{}</code></pre><!-- {e} -->",
+                    highlight::escape_html(source)
+                );
+            }
+        };
+
+        let block = match adapter.transform(source, &ctx) {
+            Ok(block) => block,
+            Err(e) => {
+                return format!(
+                    "<pre><code class=\"language-jsx\">{}</code></pre><!-- {e} -->",
+                    highlight::escape_html(source)
+                );
+            }
+        };
+
+        let prerendered = adapter.prerender(&block, &ctx).unwrap_or_default();
+        let component_html = generate_web_component(&block, &prerendered);
+
+        format!("<div class=\"preview\">{component_html}</div>")
+    }
+
+    fn write_assets(&self) -> Result<(), BuildError> {
+        let css_path = self.config.output_dir.join("styles.css");
+        let css = AssetPipeline::generate_css(&self.config.themes, &self.config.highlight_theme);
+        let css = if self.config.minify {
+            AssetPipeline::minify_css(&css).map_err(BuildError::Minify)?
+        } else {
+            css
+        };
+        std::fs::write(&css_path, css).map_err(|source| BuildError::WriteOutput {
+            path: css_path.clone(),
+            source,
+        })?;
+
+        let js_path = self.config.output_dir.join("app.js");
+        std::fs::write(&js_path, AssetPipeline::generate_js()).map_err(|source| {
+            BuildError::WriteOutput {
+                path: js_path.clone(),
+                source,
+            }
+        })?;
+
+        let theme_init_path = self.config.output_dir.join("theme-init.js");
+        std::fs::write(&theme_init_path, AssetPipeline::theme_init_script()).map_err(|source| {
+            BuildError::WriteOutput {
+                path: theme_init_path.clone(),
+                source,
+            }
+        })?;
+
+        if self.config.search {
+            let search_js_path = self.config.output_dir.join("search.js");
+            std::fs::write(&search_js_path, AssetPipeline::generate_search_js()).map_err(
+                |source| BuildError::WriteOutput {
+                    path: search_js_path.clone(),
+                    source,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Build the inverted index over `records` and write it to
+    /// `search-index.json`.
+    fn write_search_index(&self, records: &[SearchRecord]) -> Result<(), BuildError> {
+        let index = search::build_index(records);
+        let json = index.to_json()?;
+        let index_path = self.config.output_dir.join("search-index.json");
+        std::fs::write(&index_path, json).map_err(|source| BuildError::WriteOutput {
+            path: index_path.clone(),
+            source,
+        })
+    }
+}
+
+fn heading_html_tag(level: pulldown_cmark::HeadingLevel) -> &'static str {
+    match level {
+        pulldown_cmark::HeadingLevel::H2 => "h2",
+        pulldown_cmark::HeadingLevel::H3 => "h3",
+        pulldown_cmark::HeadingLevel::H4 => "h4",
+        pulldown_cmark::HeadingLevel::H1 => "h1",
+        pulldown_cmark::HeadingLevel::H5 => "h5",
+        pulldown_cmark::HeadingLevel::H6 => "h6",
+    }
+}
@@ -0,0 +1,284 @@
+//! Build-time syntax highlighting for fenced code blocks.
+//!
+//! This is a small, dependency-light tokenizer in the spirit of rustdoc's
+//! `highlight.rs`: it doesn't aim for full grammar correctness, just enough
+//! token classes (keywords, strings, comments, numbers) to make rendered
+//! code readable. Unknown languages fall through to plain, HTML-escaped
+//! text so `StaticBuilder` can always emit a `<code>` block.
+
+use std::fmt::Write as _;
+
+/// Token classes emitted as `tok-*` CSS classes; kept in sync with the
+/// `.tok-*` rules `AssetPipeline::generate_css` generates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Keyword,
+    String,
+    Comment,
+    Number,
+    Plain,
+}
+
+impl TokenKind {
+    fn css_class(self) -> Option<&'static str> {
+        match self {
+            TokenKind::Keyword => Some("tok-kw"),
+            TokenKind::String => Some("tok-str"),
+            TokenKind::Comment => Some("tok-comment"),
+            TokenKind::Number => Some("tok-num"),
+            TokenKind::Plain => None,
+        }
+    }
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "fn", "let", "mut", "pub", "struct", "enum", "impl", "trait", "use", "mod", "match", "if",
+    "else", "for", "while", "loop", "return", "self", "Self", "async", "await", "move", "ref",
+    "const", "static", "where", "dyn", "as", "in", "break", "continue",
+];
+
+const JS_KEYWORDS: &[&str] = &[
+    "function",
+    "const",
+    "let",
+    "var",
+    "if",
+    "else",
+    "for",
+    "while",
+    "return",
+    "class",
+    "extends",
+    "new",
+    "this",
+    "import",
+    "export",
+    "default",
+    "async",
+    "await",
+    "typeof",
+    "instanceof",
+    "null",
+    "undefined",
+    "true",
+    "false",
+];
+
+fn keywords_for(lang: &str) -> Option<&'static [&'static str]> {
+    match lang {
+        "rust" | "rs" => Some(RUST_KEYWORDS),
+        "js" | "javascript" | "jsx" | "ts" | "typescript" | "tsx" => Some(JS_KEYWORDS),
+        _ => None,
+    }
+}
+
+fn line_comment_for(lang: &str) -> Option<&'static str> {
+    match lang {
+        "rust" | "rs" | "js" | "javascript" | "jsx" | "ts" | "typescript" | "tsx" => Some("//"),
+        _ => None,
+    }
+}
+
+/// Tokenize `code` for `lang` and wrap each recognized token in a
+/// `<span class="tok-*">`. Returns HTML-escaped plain text (no spans) when
+/// `lang` has no registered tokenizer. Token classes are shared across
+/// languages and themes; [`token_css`] is what actually varies their
+/// color by `build.highlight_theme`.
+pub fn highlight_code(code: &str, lang: &str) -> String {
+    let Some(keywords) = keywords_for(lang) else {
+        return escape_html(code);
+    };
+    let line_comment = line_comment_for(lang);
+
+    let mut out = String::with_capacity(code.len() * 2);
+    let chars: Vec<char> = code.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(marker) = line_comment {
+            if code[byte_index(&chars, i)..].starts_with(marker) {
+                let start = i;
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                push_token(&mut out, &chars[start..i], TokenKind::Comment);
+                continue;
+            }
+        }
+
+        if c == '"' || c == '\'' {
+            let quote = c;
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != quote {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            push_token(&mut out, &chars[start..i], TokenKind::String);
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                i += 1;
+            }
+            push_token(&mut out, &chars[start..i], TokenKind::Number);
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let kind = if keywords.contains(&word.as_str()) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Plain
+            };
+            push_token(&mut out, &chars[start..i], kind);
+            continue;
+        }
+
+        let _ = write!(out, "{}", escape_char(c));
+        i += 1;
+    }
+
+    out
+}
+
+fn byte_index(chars: &[char], char_idx: usize) -> usize {
+    chars[..char_idx].iter().map(|c| c.len_utf8()).sum()
+}
+
+fn push_token(out: &mut String, chars: &[char], kind: TokenKind) {
+    let text: String = chars.iter().collect();
+    let escaped = escape_html(&text);
+    match kind.css_class() {
+        Some(class) => {
+            let _ = write!(out, "<span class=\"{class}\">{escaped}</span>");
+        }
+        None => out.push_str(&escaped),
+    }
+}
+
+fn escape_char(c: char) -> String {
+    escape_html(&c.to_string())
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// CSS rules coloring the `.tok-*` classes [`highlight_code`] emits, keyed
+/// by `build.highlight_theme`. Falls back to the `"github-dark"` palette
+/// for unrecognized theme names.
+pub fn token_css(theme: &str) -> String {
+    match theme {
+        "github-light" => r#"
+.tok-kw {
+  color: #cf222e;
+  font-weight: 600;
+}
+
+.tok-str {
+  color: #0a3069;
+}
+
+.tok-comment {
+  color: #6e7781;
+  font-style: italic;
+}
+
+.tok-num {
+  color: #0550ae;
+}
+"#
+        .to_string(),
+        "dracula" => r#"
+.tok-kw {
+  color: #ff79c6;
+  font-weight: 600;
+}
+
+.tok-str {
+  color: #f1fa8c;
+}
+
+.tok-comment {
+  color: #6272a4;
+  font-style: italic;
+}
+
+.tok-num {
+  color: #bd93f9;
+}
+"#
+        .to_string(),
+        _ => r#"
+.tok-kw {
+  color: var(--color-primary);
+  font-weight: 600;
+}
+
+.tok-str {
+  color: var(--color-token-string, #15803d);
+}
+
+.tok-comment {
+  color: var(--color-text-secondary);
+  font-style: italic;
+}
+
+.tok-num {
+  color: var(--color-token-number, #b45309);
+}
+"#
+        .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_rust_keywords() {
+        let out = highlight_code("fn main() {}", "rust");
+        assert!(out.contains("<span class=\"tok-kw\">fn</span>"));
+    }
+
+    #[test]
+    fn falls_back_to_plain_for_unknown_language() {
+        let out = highlight_code("SELECT * FROM t", "sql");
+        assert_eq!(out, "SELECT * FROM t");
+    }
+
+    #[test]
+    fn escapes_html_in_strings() {
+        let out = highlight_code(r#"let s = "<b>";"#, "rust");
+        assert!(out.contains("&lt;b&gt;"));
+    }
+
+    #[test]
+    fn token_css_varies_by_theme() {
+        let dark = token_css("github-dark");
+        let light = token_css("github-light");
+        assert_ne!(dark, light);
+        assert!(light.contains("#cf222e"));
+    }
+
+    #[test]
+    fn token_css_falls_back_for_unknown_theme() {
+        assert_eq!(token_css("not-a-real-theme"), token_css("github-dark"));
+    }
+}
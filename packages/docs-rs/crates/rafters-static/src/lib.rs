@@ -0,0 +1,16 @@
+//! Static site builder for rafters docs.
+//!
+//! Walks a docs directory of Markdown files, renders them to HTML, and emits
+//! the CSS/JS assets needed to serve the result as a standalone site.
+
+pub mod assets;
+pub mod builder;
+pub mod highlight;
+pub mod search;
+pub mod slug;
+
+pub use assets::{AssetPipeline, Theme};
+pub use builder::{BuildConfig, BuildError, BuildResult, StaticBuilder};
+pub use highlight::highlight_code;
+pub use search::{build_index, SearchRecord};
+pub use slug::slugify;
@@ -0,0 +1,194 @@
+//! Client-side full-text search index.
+//!
+//! Builds a small inverted index over the rendered pages — the same shape a
+//! docsify/verdaccio search plugin would ship — and serializes it to
+//! `search-index.json` for `search.js` to fetch once and query entirely in
+//! the browser.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "he", "in", "is", "it",
+    "its", "of", "on", "that", "the", "to", "was", "were", "will", "with",
+];
+
+const TITLE_WEIGHT: u32 = 5;
+const BODY_WEIGHT: u32 = 1;
+
+/// One searchable unit: a page, or a heading section within a page.
+pub struct SearchRecord {
+    pub title: String,
+    pub url: String,
+    pub anchor: Option<String>,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct DocEntry {
+    id: usize,
+    title: String,
+    url: String,
+    anchor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Posting {
+    doc_id: usize,
+    term_frequency: u32,
+    field_weight: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchIndex {
+    docs: Vec<DocEntry>,
+    postings: HashMap<String, Vec<Posting>>,
+}
+
+/// Elements whose entire contents (markup and text alike) should be
+/// dropped rather than indexed, e.g. `<script>` bodies.
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Strip HTML tags, leaving plain text for tokenization. Skips the
+/// contents of `SKIPPED_ELEMENTS` entirely, so component preview
+/// `<script>` bodies don't pollute the indexed text.
+pub fn strip_html(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    let mut skip_until: Option<&str> = None;
+    let mut rest = html;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(tag) = skip_until {
+            let closing = format!("</{tag}");
+            match rest.find(&closing) {
+                Some(idx) => {
+                    rest = &rest[idx..];
+                    skip_until = None;
+                }
+                None => break,
+            }
+            continue;
+        }
+
+        match c {
+            '<' => {
+                in_tag = true;
+                if let Some(tag) = SKIPPED_ELEMENTS
+                    .iter()
+                    .find(|tag| starts_with_tag(&rest[1..], tag))
+                {
+                    skip_until = Some(tag);
+                }
+            }
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
+/// Whether `rest` (the text right after a `<`) opens the named `tag`, i.e.
+/// is followed by whitespace, `>`, or end of input (so `scriptlet` doesn't
+/// match the `script` tag).
+fn starts_with_tag(rest: &str, tag: &str) -> bool {
+    rest.strip_prefix(tag).is_some_and(|after| {
+        after.starts_with(|c: char| c.is_whitespace() || c == '>') || after.is_empty()
+    })
+}
+
+/// Split on every non-alphanumeric character, lowercase, and drop
+/// stopwords. This is not full Unicode word segmentation: `don't` yields
+/// `don` and `t`.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Build an inverted index from a set of page/heading records.
+pub fn build_index(records: &[SearchRecord]) -> SearchIndex {
+    let mut docs = Vec::with_capacity(records.len());
+    let mut postings: HashMap<String, Vec<Posting>> = HashMap::new();
+
+    for (doc_id, record) in records.iter().enumerate() {
+        docs.push(DocEntry {
+            id: doc_id,
+            title: record.title.clone(),
+            url: record.url.clone(),
+            anchor: record.anchor.clone(),
+        });
+
+        let mut term_counts: HashMap<(String, u32), u32> = HashMap::new();
+        for token in tokenize(&record.title) {
+            *term_counts.entry((token, TITLE_WEIGHT)).or_insert(0) += 1;
+        }
+        for token in tokenize(&record.body) {
+            *term_counts.entry((token, BODY_WEIGHT)).or_insert(0) += 1;
+        }
+
+        for ((token, field_weight), term_frequency) in term_counts {
+            postings.entry(token).or_default().push(Posting {
+                doc_id,
+                term_frequency,
+                field_weight,
+            });
+        }
+    }
+
+    SearchIndex { docs, postings }
+}
+
+impl SearchIndex {
+    /// Serialize to the JSON shape `search.js` expects.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(strip_html("<p>hello <b>world</b></p>"), "hello world");
+    }
+
+    #[test]
+    fn skips_script_and_style_contents() {
+        let html = "<p>hello</p><script type=\"module\">const x = 1;</script><style>.a{color:red}</style><p>world</p>";
+        assert_eq!(strip_html(html), "helloworld");
+    }
+
+    #[test]
+    fn weights_title_matches_higher_than_body() {
+        let records = vec![SearchRecord {
+            title: "Routing".to_string(),
+            url: "/routing".to_string(),
+            anchor: None,
+            body: "This page covers routing basics.".to_string(),
+        }];
+        let index = build_index(&records);
+        let routing_postings = &index.postings["routing"];
+        assert_eq!(routing_postings.len(), 2);
+        assert!(routing_postings
+            .iter()
+            .any(|p| p.field_weight == TITLE_WEIGHT));
+        assert!(routing_postings
+            .iter()
+            .any(|p| p.field_weight == BODY_WEIGHT));
+    }
+
+    #[test]
+    fn drops_stopwords() {
+        let tokens = tokenize("the quick fox and the dog");
+        assert!(!tokens.iter().any(|t| t == "the" || t == "and"));
+    }
+}
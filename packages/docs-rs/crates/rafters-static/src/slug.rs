@@ -0,0 +1,52 @@
+//! Heading slug generation for anchor links and the scroll-spy TOC.
+
+use std::collections::HashMap;
+
+/// Lowercase `text`, strip punctuation, collapse whitespace to hyphens, and
+/// de-duplicate against `used` with a numeric suffix (`"intro"`,
+/// `"intro-1"`, `"intro-2"`, ...), the same scheme docsify's slugify uses.
+pub fn slugify(text: &str, used: &mut HashMap<String, u32>) -> String {
+    let stripped: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut slug = stripped.split_whitespace().collect::<Vec<_>>().join("-");
+    if slug.is_empty() {
+        slug = "section".to_string();
+    }
+
+    let count = used.entry(slug.clone()).or_insert(0);
+    let result = if *count == 0 {
+        slug.clone()
+    } else {
+        format!("{slug}-{count}")
+    };
+    *count += 1;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugifies_punctuation_and_whitespace() {
+        let mut used = HashMap::new();
+        assert_eq!(slugify("Getting Started!", &mut used), "getting-started");
+    }
+
+    #[test]
+    fn de_duplicates_with_numeric_suffix() {
+        let mut used = HashMap::new();
+        assert_eq!(slugify("Overview", &mut used), "overview");
+        assert_eq!(slugify("Overview", &mut used), "overview-1");
+        assert_eq!(slugify("Overview", &mut used), "overview-2");
+    }
+
+    #[test]
+    fn falls_back_to_section_for_empty_headings() {
+        let mut used = HashMap::new();
+        assert_eq!(slugify("!!!", &mut used), "section");
+    }
+}